@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+
+use crate::rdata::QueryType;
+use crate::{presentation, Class, Error, Header, Name, Opcode, QueryClass, RData, ResponseCode};
+
+/// Incrementally builds the wire form of a DNS packet
+#[derive(Debug)]
+pub struct Builder {
+    buf: Vec<u8>,
+    header: Header,
+    /// Maps a name's remaining labels to the offset it was first written
+    /// at, so a later name sharing that suffix can point back to it
+    /// instead of repeating it (RFC1035 §4.1.4 compression).
+    compression: HashMap<Vec<Vec<u8>>, u16>,
+}
+
+impl Builder {
+    /// Start building a query packet with the given transaction id
+    pub fn new_query(id: u16, recursion_desired: bool) -> Builder {
+        let header = Header {
+            id,
+            query: true,
+            opcode: Opcode::StandardQuery,
+            authoritative: false,
+            truncated: false,
+            recursion_desired,
+            recursion_available: false,
+            authenticated_data: false,
+            checking_disabled: false,
+            response_code: ResponseCode::NoError,
+            questions: 0,
+            answers: 0,
+            nameservers: 0,
+            additional: 0,
+        };
+        Builder { buf: vec![0u8; Header::size()], header, compression: HashMap::new() }
+    }
+
+    /// Start building a response packet with the given transaction id,
+    /// echoing the query's opcode and RD bit as a server normally would.
+    pub fn new_response(
+        id: u16,
+        opcode: Opcode,
+        recursion_desired: bool,
+        authoritative: bool,
+        recursion_available: bool,
+        response_code: ResponseCode,
+    ) -> Builder {
+        let header = Header {
+            id,
+            query: false,
+            opcode,
+            authoritative,
+            truncated: false,
+            recursion_desired,
+            recursion_available,
+            authenticated_data: false,
+            checking_disabled: false,
+            response_code,
+            questions: 0,
+            answers: 0,
+            nameservers: 0,
+            additional: 0,
+        };
+        Builder { buf: vec![0u8; Header::size()], header, compression: HashMap::new() }
+    }
+
+    /// Add a question to the packet being built
+    pub fn add_question(&mut self, qname: &str, qtype: QueryType, qclass: QueryClass) {
+        self.write_name(qname);
+        self.buf.extend_from_slice(&u16::from(qtype).to_be_bytes());
+        self.buf.extend_from_slice(&u16::from(qclass).to_be_bytes());
+        self.header.questions += 1;
+    }
+
+    /// Add a record to the answer section
+    pub fn add_answer(&mut self, name: &str, class: Class, ttl: u32, data: &RData) {
+        self.add_record(name, class, ttl, data);
+        self.header.answers += 1;
+    }
+
+    /// Add a record to the authority (nameserver) section
+    pub fn add_nameserver(&mut self, name: &str, class: Class, ttl: u32, data: &RData) {
+        self.add_record(name, class, ttl, data);
+        self.header.nameservers += 1;
+    }
+
+    /// Add a record to the additional section
+    pub fn add_additional(&mut self, name: &str, class: Class, ttl: u32, data: &RData) {
+        self.add_record(name, class, ttl, data);
+        self.header.additional += 1;
+    }
+
+    fn add_record(&mut self, name: &str, class: Class, ttl: u32, data: &RData) {
+        self.write_name(name);
+        self.buf.extend_from_slice(&u16::from(data.type_code()).to_be_bytes());
+        self.buf.extend_from_slice(&u16::from(class).to_be_bytes());
+        self.buf.extend_from_slice(&ttl.to_be_bytes());
+
+        let rdlength_pos = self.buf.len();
+        self.buf.extend_from_slice(&[0u8; 2]);
+        let rdata_start = self.buf.len();
+        self.write_rdata(data);
+        let rdlength = (self.buf.len() - rdata_start) as u16;
+        self.buf[rdlength_pos..rdlength_pos + 2].copy_from_slice(&rdlength.to_be_bytes());
+    }
+
+    fn write_rdata(&mut self, data: &RData) {
+        use crate::RData::*;
+        match data {
+            A(r) => self.buf.extend_from_slice(&r.0.octets()),
+            AAAA(r) => self.buf.extend_from_slice(&r.0.octets()),
+            CNAME(r) => self.write_name_field(&r.0, true),
+            NS(r) => self.write_name_field(&r.0, true),
+            PTR(r) => self.write_name_field(&r.0, true),
+            MX(r) => {
+                self.buf.extend_from_slice(&r.preference.to_be_bytes());
+                self.write_name_field(&r.exchange, true);
+            }
+            SRV(r) => {
+                self.buf.extend_from_slice(&r.priority.to_be_bytes());
+                self.buf.extend_from_slice(&r.weight.to_be_bytes());
+                self.buf.extend_from_slice(&r.port.to_be_bytes());
+                // RFC2782: the target name of an SRV record must not be compressed.
+                self.write_name_field(&r.target, false);
+            }
+            SOA(r) => {
+                self.write_name_field(&r.primary_ns, true);
+                self.write_name_field(&r.mailbox, true);
+                self.buf.extend_from_slice(&r.serial.to_be_bytes());
+                self.buf.extend_from_slice(&r.refresh.to_be_bytes());
+                self.buf.extend_from_slice(&r.retry.to_be_bytes());
+                self.buf.extend_from_slice(&r.expire.to_be_bytes());
+                self.buf.extend_from_slice(&r.minimum_ttl.to_be_bytes());
+            }
+            TXT(r) => {
+                for string in &r.0 {
+                    self.buf.push(string.len() as u8);
+                    self.buf.extend_from_slice(string);
+                }
+            }
+            DNSKEY(r) => {
+                self.buf.extend_from_slice(&r.flags.to_be_bytes());
+                self.buf.push(r.protocol);
+                self.buf.push(r.algorithm);
+                self.buf.extend_from_slice(r.public_key);
+            }
+            DS(r) => {
+                self.buf.extend_from_slice(&r.key_tag.to_be_bytes());
+                self.buf.push(r.algorithm);
+                self.buf.push(r.digest_type);
+                self.buf.extend_from_slice(r.digest);
+            }
+            RRSIG(r) => {
+                self.buf.extend_from_slice(&u16::from(r.type_covered).to_be_bytes());
+                self.buf.push(r.algorithm);
+                self.buf.push(r.labels);
+                self.buf.extend_from_slice(&r.original_ttl.to_be_bytes());
+                self.buf.extend_from_slice(&r.signature_expiration.to_be_bytes());
+                self.buf.extend_from_slice(&r.signature_inception.to_be_bytes());
+                self.buf.extend_from_slice(&r.key_tag.to_be_bytes());
+                // RFC4034 §3.1.7: the signer's name must not be compressed.
+                self.write_name_field(&r.signers_name, false);
+                self.buf.extend_from_slice(r.signature);
+            }
+            NSEC(r) => {
+                // RFC4034 §6.2: the next-owner name must not be compressed.
+                self.write_name_field(&r.next_domain_name, false);
+                self.buf.extend_from_slice(&crate::rdata::bitmap::encode(&r.types));
+            }
+            NSEC3(r) => {
+                self.buf.push(r.hash_algorithm);
+                self.buf.push(r.flags);
+                self.buf.extend_from_slice(&r.iterations.to_be_bytes());
+                self.buf.push(r.salt.len() as u8);
+                self.buf.extend_from_slice(r.salt);
+                self.buf.push(r.next_hashed_owner_name.len() as u8);
+                self.buf.extend_from_slice(r.next_hashed_owner_name);
+                self.buf.extend_from_slice(&crate::rdata::bitmap::encode(&r.types));
+            }
+            Unknown { data, .. } => self.buf.extend_from_slice(data),
+            OPT(_) => panic!("use Builder::add_opt for EDNS0 OPT records"),
+        }
+    }
+
+    /// Add an EDNS0 OPT pseudo-record to the additional section (RFC6891),
+    /// advertising `payload_size` as the largest UDP response we accept,
+    /// optionally setting the DO (DNSSEC OK) bit, and attaching zero or
+    /// more `{option-code, data}` options (e.g. EDNS Client Subnet,
+    /// COOKIE).
+    pub fn add_opt(&mut self, payload_size: u16, dnssec_ok: bool, options: &[(u16, &[u8])]) {
+        self.buf.push(0); // OPT's owner name is always the root
+        self.buf.extend_from_slice(&u16::from(crate::rdata::Type::OPT).to_be_bytes());
+        self.buf.extend_from_slice(&payload_size.to_be_bytes());
+
+        let mut ttl = [0u8; 4];
+        ttl[0] = 0; // extended_rcode, filled in by the caller's transport layer
+        ttl[1] = 0; // EDNS version 0
+        if dnssec_ok {
+            ttl[2] = 0x80;
+        }
+        self.buf.extend_from_slice(&ttl);
+
+        let rdlength_pos = self.buf.len();
+        self.buf.extend_from_slice(&[0u8; 2]);
+        let rdata_start = self.buf.len();
+        for &(code, data) in options {
+            self.buf.extend_from_slice(&code.to_be_bytes());
+            self.buf.extend_from_slice(&(data.len() as u16).to_be_bytes());
+            self.buf.extend_from_slice(data);
+        }
+        let rdlength = (self.buf.len() - rdata_start) as u16;
+        self.buf[rdlength_pos..rdlength_pos + 2].copy_from_slice(&rdlength.to_be_bytes());
+
+        self.header.additional += 1;
+    }
+
+    /// Add a resource record of a TYPE this crate doesn't model to the
+    /// additional section, e.g. CAA or HTTPS/SVCB, following the RFC3597
+    /// escape hatch that lets unknown rdata round-trip through the crate.
+    pub fn add_unknown_additional(&mut self, name: &str, type_code: u16, class: QueryClass, ttl: u32, rdata: &[u8]) {
+        self.write_name(name);
+        self.buf.extend_from_slice(&type_code.to_be_bytes());
+        self.buf.extend_from_slice(&u16::from(class).to_be_bytes());
+        self.buf.extend_from_slice(&ttl.to_be_bytes());
+        self.buf.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        self.buf.extend_from_slice(rdata);
+        self.header.additional += 1;
+    }
+
+    /// Parse the RFC3597 `\# <length> <hex>` generic RDATA representation
+    /// into raw bytes suitable for
+    /// [`add_unknown_additional`](Builder::add_unknown_additional).
+    pub fn parse_generic_rdata(text: &str) -> Result<Vec<u8>, Error> {
+        presentation::parse_generic_rdata(text)
+    }
+
+    /// Encode `name` the same way [`Name::from_unicode`] would (decoding
+    /// `\.`/`\DDD` escapes and Punycode-encoding non-ASCII labels) and
+    /// write it out, rather than re-deriving name validation here.
+    fn write_name(&mut self, name: &str) {
+        let parsed = Name::from_unicode(name).unwrap_or_else(|e| panic!("invalid name {:?}: {}", name, e));
+        self.write_name_field(&parsed, true);
+    }
+
+    fn write_name_field(&mut self, name: &Name, compress: bool) {
+        let labels: Vec<Vec<u8>> = name.labels().map(<[u8]>::to_vec).collect();
+        self.write_label_seq(&labels, compress);
+    }
+
+    fn write_label_seq(&mut self, labels: &[Vec<u8>], compress: bool) {
+        if labels.is_empty() {
+            self.buf.push(0);
+            return;
+        }
+        if compress {
+            if let Some(&offset) = self.compression.get(labels) {
+                self.buf.push(0xc0 | (offset >> 8) as u8);
+                self.buf.push((offset & 0xff) as u8);
+                return;
+            }
+            // Pointers are only 14 bits wide, so suffixes past that
+            // offset simply can't be pointed to.
+            if self.buf.len() <= 0x3fff {
+                self.compression.insert(labels.to_vec(), self.buf.len() as u16);
+            }
+        }
+        self.buf.push(labels[0].len() as u8);
+        self.buf.extend_from_slice(&labels[0]);
+        self.write_label_seq(&labels[1..], compress);
+    }
+
+    /// Current encoded size of the packet in bytes
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// `true` if nothing but the header has been written yet
+    pub fn is_empty(&self) -> bool {
+        self.buf.len() == Header::size()
+    }
+
+    /// Finish building and return the packet's bytes
+    pub fn build(mut self) -> Vec<u8> {
+        self.header.write(&mut self.buf[..Header::size()]);
+        self.buf
+    }
+
+    /// Finish building, truncating to `max_size` bytes and setting the TC
+    /// bit if the packet doesn't fit, the way a server has to when a
+    /// response would overflow its UDP size budget.
+    pub fn build_truncated(mut self, max_size: usize) -> Vec<u8> {
+        self.header.write(&mut self.buf[..Header::size()]);
+        if self.buf.len() > max_size {
+            self.buf.truncate(max_size);
+            Header::set_truncated(&mut self.buf);
+        }
+        self.buf
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+
+    use super::Builder;
+    use crate::rdata::{QueryType, A};
+    use crate::{Class, Header, Opcode, Packet, QueryClass, RData, ResponseCode};
+
+    #[test]
+    fn add_opt_round_trips_through_parse() {
+        let mut builder = Builder::new_query(1, true);
+        builder.add_question("example.com", QueryType::A, QueryClass::IN);
+        builder.add_opt(4096, true, &[(10, b"\xab\xcd")]);
+        let bytes = builder.build();
+        let packet = Packet::parse(&bytes).unwrap();
+
+        assert_eq!(packet.additional.len(), 1);
+        let opt = match &packet.additional[0].data {
+            crate::RData::OPT(opt) => opt,
+            other => panic!("expected OPT, got {:?}", other),
+        };
+        assert_eq!(opt.udp_payload_size, 4096);
+        assert!(opt.dnssec_ok);
+        let options: Vec<_> = opt.options().collect();
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].code, 10);
+        assert_eq!(options[0].data, b"\xab\xcd");
+    }
+
+    #[test]
+    fn add_answer_round_trips_through_parse() {
+        let mut builder = Builder::new_response(1, Opcode::StandardQuery, true, true, true, ResponseCode::NoError);
+        builder.add_answer("example.com", Class::IN, 300, &RData::A(A(Ipv4Addr::new(93, 184, 216, 34))));
+        let bytes = builder.build();
+        let packet = Packet::parse(&bytes).unwrap();
+
+        assert_eq!(packet.answers.len(), 1);
+        assert_eq!(packet.answers[0].name.to_string(), "example.com.");
+        assert_eq!(packet.answers[0].ttl, 300);
+        assert_eq!(packet.answers[0].cls, Class::IN);
+        match packet.answers[0].data {
+            RData::A(A(addr)) => assert_eq!(addr, Ipv4Addr::new(93, 184, 216, 34)),
+            ref other => panic!("expected A, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn add_nameserver_and_additional_land_in_their_own_sections() {
+        let mut builder = Builder::new_response(1, Opcode::StandardQuery, true, true, true, ResponseCode::NoError);
+        builder.add_nameserver("ns.example.com", Class::IN, 300, &RData::A(A(Ipv4Addr::new(1, 1, 1, 1))));
+        builder.add_additional("glue.example.com", Class::IN, 300, &RData::A(A(Ipv4Addr::new(2, 2, 2, 2))));
+        let bytes = builder.build();
+        let packet = Packet::parse(&bytes).unwrap();
+
+        assert_eq!(packet.answers.len(), 0);
+        assert_eq!(packet.nameservers.len(), 1);
+        assert_eq!(packet.additional.len(), 1);
+        assert_eq!(packet.nameservers[0].name.to_string(), "ns.example.com.");
+        assert_eq!(packet.additional[0].name.to_string(), "glue.example.com.");
+    }
+
+    #[test]
+    fn repeated_name_suffix_is_written_as_a_compression_pointer() {
+        let mut builder = Builder::new_response(1, Opcode::StandardQuery, true, true, true, ResponseCode::NoError);
+        builder.add_answer("a.example.com", Class::IN, 300, &RData::A(A(Ipv4Addr::new(1, 1, 1, 1))));
+        builder.add_answer("b.example.com", Class::IN, 300, &RData::A(A(Ipv4Addr::new(2, 2, 2, 2))));
+        let bytes = builder.build();
+
+        // "example.com" was already written by the first record, so the
+        // second record's owner name must point back to it instead of
+        // repeating the labels.
+        let marker = bytes.windows(2).position(|w| w[0] == 0xc0).unwrap();
+        assert!(marker > Header::size());
+
+        let packet = Packet::parse(&bytes).unwrap();
+        assert_eq!(packet.answers[1].name.to_string(), "b.example.com.");
+    }
+
+    #[test]
+    fn build_truncated_sets_tc_bit_when_over_budget() {
+        let mut builder = Builder::new_response(1, Opcode::StandardQuery, true, true, true, ResponseCode::NoError);
+        builder.add_answer("example.com", Class::IN, 300, &RData::A(A(Ipv4Addr::new(1, 1, 1, 1))));
+        let full_len = builder.len();
+        let bytes = builder.build_truncated(Header::size());
+
+        assert_eq!(bytes.len(), Header::size());
+        assert!(bytes.len() < full_len);
+        let header = Header::parse(&bytes).unwrap();
+        assert!(header.truncated);
+    }
+}
@@ -0,0 +1,179 @@
+//! Canonical wire-form serialization of names and RRsets, per RFC4034 §6.
+//!
+//! This is the byte stream an RRSIG signature is actually computed and
+//! verified over: the RRSIG RDATA (minus the signature itself) followed
+//! by the canonicalized, sorted RRset it covers.
+
+use crate::rdata::bitmap;
+use crate::rdata::RData;
+use crate::structs::ResourceRecord;
+
+/// Write a single record's RDATA in canonical form: embedded names are
+/// expanded and lowercased, everything else is copied through as-is.
+fn write_rdata_canonical(data: &RData, buf: &mut Vec<u8>) {
+    use RData::*;
+    match data {
+        A(r) => buf.extend_from_slice(&r.0.octets()),
+        AAAA(r) => buf.extend_from_slice(&r.0.octets()),
+        CNAME(r) => r.0.write_canonical(buf),
+        NS(r) => r.0.write_canonical(buf),
+        PTR(r) => r.0.write_canonical(buf),
+        MX(r) => {
+            buf.extend_from_slice(&r.preference.to_be_bytes());
+            r.exchange.write_canonical(buf);
+        }
+        SRV(r) => {
+            buf.extend_from_slice(&r.priority.to_be_bytes());
+            buf.extend_from_slice(&r.weight.to_be_bytes());
+            buf.extend_from_slice(&r.port.to_be_bytes());
+            r.target.write_canonical(buf);
+        }
+        SOA(r) => {
+            r.primary_ns.write_canonical(buf);
+            r.mailbox.write_canonical(buf);
+            buf.extend_from_slice(&r.serial.to_be_bytes());
+            buf.extend_from_slice(&r.refresh.to_be_bytes());
+            buf.extend_from_slice(&r.retry.to_be_bytes());
+            buf.extend_from_slice(&r.expire.to_be_bytes());
+            buf.extend_from_slice(&r.minimum_ttl.to_be_bytes());
+        }
+        TXT(r) => {
+            for string in &r.0 {
+                buf.push(string.len() as u8);
+                buf.extend_from_slice(string);
+            }
+        }
+        DNSKEY(r) => {
+            buf.extend_from_slice(&r.flags.to_be_bytes());
+            buf.push(r.protocol);
+            buf.push(r.algorithm);
+            buf.extend_from_slice(r.public_key);
+        }
+        DS(r) => {
+            buf.extend_from_slice(&r.key_tag.to_be_bytes());
+            buf.push(r.algorithm);
+            buf.push(r.digest_type);
+            buf.extend_from_slice(r.digest);
+        }
+        RRSIG(r) => {
+            buf.extend_from_slice(&u16::from(r.type_covered).to_be_bytes());
+            buf.push(r.algorithm);
+            buf.push(r.labels);
+            buf.extend_from_slice(&r.original_ttl.to_be_bytes());
+            buf.extend_from_slice(&r.signature_expiration.to_be_bytes());
+            buf.extend_from_slice(&r.signature_inception.to_be_bytes());
+            buf.extend_from_slice(&r.key_tag.to_be_bytes());
+            r.signers_name.write_canonical(buf);
+            buf.extend_from_slice(r.signature);
+        }
+        NSEC(r) => {
+            r.next_domain_name.write_canonical(buf);
+            buf.extend_from_slice(&bitmap::encode(&r.types));
+        }
+        NSEC3(r) => {
+            buf.push(r.hash_algorithm);
+            buf.push(r.flags);
+            buf.extend_from_slice(&r.iterations.to_be_bytes());
+            buf.push(r.salt.len() as u8);
+            buf.extend_from_slice(r.salt);
+            buf.push(r.next_hashed_owner_name.len() as u8);
+            buf.extend_from_slice(r.next_hashed_owner_name);
+            buf.extend_from_slice(&bitmap::encode(&r.types));
+        }
+        // OPT is a pseudo-record that never forms part of a signed RRset.
+        OPT(_) => {}
+        // RFC3597 generic RDATA has no embedded names to canonicalize.
+        Unknown { data, .. } => buf.extend_from_slice(data),
+    }
+}
+
+/// Canonicalize an RRset ahead of RRSIG signature verification.
+///
+/// `records` must share the same owner name, type and class. Per RFC4034
+/// §6.2-6.3 this lowercases the owner name, replaces each TTL with the
+/// RRSIG's `original_ttl`, expands any names embedded in the RDATA to
+/// canonical form, and sorts the records by treating their canonical
+/// RDATA as an unsigned left-justified byte string (a shorter prefix
+/// sorts first). The result is the byte stream that gets appended after
+/// the RRSIG RDATA (with the signature field itself omitted) before
+/// hashing and verifying.
+pub fn canonical_rrset(records: &[ResourceRecord], original_ttl: u32) -> Vec<u8> {
+    let mut owner = Vec::new();
+    if let Some(first) = records.first() {
+        first.name.write_canonical(&mut owner);
+    }
+
+    let mut rdatas: Vec<Vec<u8>> = records
+        .iter()
+        .map(|rr| {
+            let mut rdata = Vec::new();
+            write_rdata_canonical(&rr.data, &mut rdata);
+            rdata
+        })
+        .collect();
+    rdatas.sort();
+
+    let mut out = Vec::new();
+    for (rr, rdata) in records.iter().zip(rdatas.iter()) {
+        out.extend_from_slice(&owner);
+        out.extend_from_slice(&u16::from(rr.data.type_code()).to_be_bytes());
+        out.extend_from_slice(&u16::from(rr.cls).to_be_bytes());
+        out.extend_from_slice(&original_ttl.to_be_bytes());
+        out.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        out.extend_from_slice(rdata);
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+    use std::str::FromStr;
+
+    use super::canonical_rrset;
+    use crate::rdata::RData;
+    use crate::structs::ResourceRecord;
+    use crate::{Class, Name};
+
+    fn a_record(name: &str, ttl: u32, addr: Ipv4Addr) -> ResourceRecord<'static> {
+        ResourceRecord {
+            name: Name::from_str(name).unwrap(),
+            multicast_unique: false,
+            cls: Class::IN,
+            ttl,
+            data: RData::A(crate::rdata::A(addr)),
+        }
+    }
+
+    #[test]
+    fn lowercases_owner_and_replaces_ttl() {
+        let records = vec![a_record("WWW.Example.com", 300, Ipv4Addr::new(1, 2, 3, 4))];
+        let canonical = canonical_rrset(&records, 3600);
+
+        // owner name, lowercased and uncompressed
+        let mut expected = Vec::new();
+        Name::from_str("www.example.com").unwrap().write_canonical(&mut expected);
+        assert!(canonical.starts_with(&expected));
+
+        // TTL in the canonical form is the RRSIG's original_ttl, not the
+        // record's own (possibly decremented-by-caching) TTL.
+        let ttl_offset = expected.len() + 2 /* type */ + 2 /* class */;
+        assert_eq!(&canonical[ttl_offset..ttl_offset + 4], &3600u32.to_be_bytes());
+    }
+
+    #[test]
+    fn sorts_records_by_canonical_rdata() {
+        let records = vec![
+            a_record("example.com", 300, Ipv4Addr::new(2, 0, 0, 0)),
+            a_record("example.com", 300, Ipv4Addr::new(1, 0, 0, 0)),
+        ];
+        let canonical = canonical_rrset(&records, 300);
+
+        // Each entry here is owner(13) + type(2) + class(2) + ttl(4) +
+        // rdlength(2) + rdata(4) = 27 bytes; the 1.0.0.0 record's RDATA
+        // sorts before 2.0.0.0's, so it must come first in the output.
+        let entry_len = 13 + 2 + 2 + 4 + 2 + 4;
+        let first_rdata = &canonical[entry_len - 4..entry_len];
+        assert_eq!(first_rdata, &[1, 0, 0, 0]);
+    }
+}
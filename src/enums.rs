@@ -0,0 +1,170 @@
+//! Small numeric enumerations shared by the header and rdata modules
+
+use std::fmt;
+
+/// The four-bit operation code carried in the header
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Opcode {
+    StandardQuery,
+    InverseQuery,
+    ServerStatusRequest,
+    Reserved(u16),
+}
+
+impl From<u16> for Opcode {
+    fn from(code: u16) -> Opcode {
+        use self::Opcode::*;
+        match code {
+            0 => StandardQuery,
+            1 => InverseQuery,
+            2 => ServerStatusRequest,
+            x => Reserved(x),
+        }
+    }
+}
+
+impl From<Opcode> for u16 {
+    fn from(opcode: Opcode) -> u16 {
+        use self::Opcode::*;
+        match opcode {
+            StandardQuery => 0,
+            InverseQuery => 1,
+            ServerStatusRequest => 2,
+            Reserved(x) => x,
+        }
+    }
+}
+
+/// The four-bit response code carried in the header
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ResponseCode {
+    NoError,
+    FormatError,
+    ServerFailure,
+    NameError,
+    NotImplemented,
+    Refused,
+    Reserved(u8),
+}
+
+impl From<u8> for ResponseCode {
+    fn from(code: u8) -> ResponseCode {
+        use self::ResponseCode::*;
+        match code {
+            0 => NoError,
+            1 => FormatError,
+            2 => ServerFailure,
+            3 => NameError,
+            4 => NotImplemented,
+            5 => Refused,
+            x => Reserved(x),
+        }
+    }
+}
+
+impl From<ResponseCode> for u8 {
+    fn from(code: ResponseCode) -> u8 {
+        use self::ResponseCode::*;
+        match code {
+            NoError => 0,
+            FormatError => 1,
+            ServerFailure => 2,
+            NameError => 3,
+            NotImplemented => 4,
+            Refused => 5,
+            Reserved(x) => x,
+        }
+    }
+}
+
+/// The CLASS field of a resource record
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Class {
+    IN,
+    CS,
+    CH,
+    HS,
+    /// A value this parser doesn't have a named variant for. Resource
+    /// records on the wire aren't restricted to the classes above, so
+    /// this is a fallback rather than a parse error.
+    Reserved(u16),
+}
+
+impl From<u16> for Class {
+    fn from(value: u16) -> Class {
+        use self::Class::*;
+        match value {
+            1 => IN,
+            2 => CS,
+            3 => CH,
+            4 => HS,
+            x => Reserved(x),
+        }
+    }
+}
+
+impl From<Class> for u16 {
+    fn from(value: Class) -> u16 {
+        use self::Class::*;
+        match value {
+            IN => 1,
+            CS => 2,
+            CH => 3,
+            HS => 4,
+            Reserved(x) => x,
+        }
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::Class::*;
+        match self {
+            IN => f.write_str("IN"),
+            CS => f.write_str("CS"),
+            CH => f.write_str("CH"),
+            HS => f.write_str("HS"),
+            Reserved(x) => write!(f, "CLASS{}", x),
+        }
+    }
+}
+
+/// The CLASS field of a question, which additionally allows `*` (Any)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum QueryClass {
+    IN,
+    CS,
+    CH,
+    HS,
+    Any,
+    /// A value this parser doesn't have a named variant for.
+    Reserved(u16),
+}
+
+impl From<u16> for QueryClass {
+    fn from(value: u16) -> QueryClass {
+        use self::QueryClass::*;
+        match value {
+            1 => IN,
+            2 => CS,
+            3 => CH,
+            4 => HS,
+            255 => Any,
+            x => Reserved(x),
+        }
+    }
+}
+
+impl From<QueryClass> for u16 {
+    fn from(value: QueryClass) -> u16 {
+        use self::QueryClass::*;
+        match value {
+            IN => 1,
+            CS => 2,
+            CH => 3,
+            HS => 4,
+            Any => 255,
+            Reserved(x) => x,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+quick_error! {
+    /// Errors that can occur while parsing or building a DNS packet
+    #[derive(Debug)]
+    pub enum Error {
+        /// Header section is smaller than the mandatory 12 bytes
+        HeaderTooShort {
+            display("header is too short")
+        }
+        /// Some header bits marked reserved in the spec are non-zero
+        ReservedBitsAreNonZero {
+            display("reserved bits in the header are non-zero")
+        }
+        /// A read ran past the end of the packet
+        PacketTooShort {
+            display("packet is shorter than a field being read from it")
+        }
+        /// RDATA length doesn't match what the record type expects
+        WrongRdataLength {
+            display("wrong rdata length for the resource record type")
+        }
+        /// A name label used a length byte this parser doesn't understand
+        UnknownLabelFormat {
+            display("unknown label format")
+        }
+        /// A single name label is longer than the 63 byte limit
+        LabelTooLong {
+            display("label is longer than 63 bytes")
+        }
+        /// The fully-assembled name is longer than the 255 byte limit
+        NameTooLong {
+            display("name is longer than 255 bytes")
+        }
+        /// A compression pointer points forward or outside the packet
+        BadPointer {
+            display("compression pointer is invalid")
+        }
+        /// A compression pointer was used where the RFC forbids it
+        CompressionNotAllowed {
+            display("compression pointers are not allowed in this context")
+        }
+        /// A label could not be represented in IDNA (UTS-46) form
+        InvalidIdnaLabel {
+            display("label cannot be represented in IDNA")
+        }
+    }
+}
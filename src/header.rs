@@ -100,7 +100,7 @@ impl Header {
     // shouldn't this method be non-public?
     pub fn set_truncated(data: &mut [u8]) {
         let oldflags = u16::from_be_bytes(data[2..4].try_into().unwrap());
-        data[2..4].copy_from_slice(&(oldflags & flag::TRUNCATED as u16).to_be_bytes());
+        data[2..4].copy_from_slice(&(oldflags | flag::TRUNCATED).to_be_bytes());
     }
     /// Returns a size of the header (always 12 bytes)
     pub fn size() -> usize {
@@ -22,11 +22,14 @@ extern crate matches;
 extern crate quick_error;
 
 mod builder;
+mod canonical;
 mod enums;
 mod error;
 mod header;
 mod name;
 mod parser;
+mod presentation;
+mod punycode;
 mod structs;
 
 /// Data types and methods for handling the RData field
@@ -34,6 +37,7 @@ mod structs;
 pub mod rdata;
 
 pub use crate::builder::Builder;
+pub use crate::canonical::canonical_rrset;
 pub use crate::enums::{Class, Opcode, QueryClass, ResponseCode};
 pub use crate::error::Error;
 pub use crate::header::Header;
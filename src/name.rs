@@ -0,0 +1,322 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// A DNS name
+///
+/// Labels are borrowed from the original packet when parsed off the
+/// wire, so no allocation is needed in that path; names built from text
+/// (see [`FromStr`]) own their labels instead.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Name<'a> {
+    labels: Vec<Cow<'a, [u8]>>,
+}
+
+impl<'a> Name<'a> {
+    /// Parse a name starting at `pos` in `packet`, following compression
+    /// pointers. Returns the name and the offset right after it (before
+    /// any pointer jump).
+    pub fn parse(packet: &'a [u8], pos: usize) -> Result<(Name<'a>, usize), Error> {
+        Name::parse_opt(packet, pos, true)
+    }
+
+    /// Parse a name the same way as [`parse`](Name::parse), but treat a
+    /// compression pointer as an error instead of following it.
+    ///
+    /// RFC4034 requires the owner and signer names embedded in RRSIG and
+    /// NSEC rdata to be uncompressed.
+    pub fn parse_uncompressed(packet: &'a [u8], pos: usize) -> Result<(Name<'a>, usize), Error> {
+        Name::parse_opt(packet, pos, false)
+    }
+
+    fn parse_opt(packet: &'a [u8], pos: usize, allow_pointers: bool) -> Result<(Name<'a>, usize), Error> {
+        let mut labels = Vec::new();
+        let mut cur = pos;
+        let mut end = None;
+        let mut jumps = 0;
+        loop {
+            let len = *packet.get(cur).ok_or(Error::PacketTooShort)? as usize;
+            match len {
+                0 => {
+                    cur += 1;
+                    if end.is_none() {
+                        end = Some(cur);
+                    }
+                    break;
+                }
+                0x01..=0x3f => {
+                    let start = cur + 1;
+                    let stop = start + len;
+                    let label = packet.get(start..stop).ok_or(Error::PacketTooShort)?;
+                    labels.push(Cow::Borrowed(label));
+                    cur = stop;
+                }
+                0xc0..=0xff => {
+                    if !allow_pointers {
+                        return Err(Error::CompressionNotAllowed);
+                    }
+                    let lo = *packet.get(cur + 1).ok_or(Error::PacketTooShort)? as usize;
+                    let offset = ((len & 0x3f) << 8) | lo;
+                    if end.is_none() {
+                        end = Some(cur + 2);
+                    }
+                    // Pointers must always point backwards, which also
+                    // bounds the number of jumps we can take.
+                    if offset >= cur {
+                        return Err(Error::BadPointer);
+                    }
+                    jumps += 1;
+                    if jumps > 128 {
+                        return Err(Error::BadPointer);
+                    }
+                    cur = offset;
+                }
+                _ => return Err(Error::UnknownLabelFormat),
+            }
+        }
+        let wire_len: usize = labels.iter().map(|l| l.len() + 1).sum::<usize>() + 1;
+        if wire_len > 255 {
+            return Err(Error::NameTooLong);
+        }
+        Ok((Name { labels }, end.unwrap()))
+    }
+
+    /// Iterate over the raw label bytes, in root-to-leaf (wire) order,
+    /// not including the terminating root label.
+    pub fn labels(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.labels.iter().map(|l| l.as_ref())
+    }
+
+    /// `true` for the root name
+    pub fn is_root(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Write this name in RFC4034 §6.2 canonical form: uncompressed,
+    /// with every ASCII letter lowercased. Used when assembling the
+    /// canonical RRset an RRSIG signature is verified against.
+    pub fn write_canonical(&self, buf: &mut Vec<u8>) {
+        for label in &self.labels {
+            buf.push(label.len() as u8);
+            buf.extend(label.iter().map(u8::to_ascii_lowercase));
+        }
+        buf.push(0);
+    }
+
+    fn push_label(labels: &mut Vec<Cow<'static, [u8]>>, label: Vec<u8>) -> Result<(), Error> {
+        if label.len() > 63 {
+            return Err(Error::LabelTooLong);
+        }
+        labels.push(Cow::Owned(label));
+        Ok(())
+    }
+
+    /// Build a name from a Unicode domain such as `münchen.de` or
+    /// `例え.jp`, the way a user would type it. `\.`/`\DDD` escapes (the
+    /// same presentation format [`FromStr`] accepts) are decoded first;
+    /// every resulting label is then case-folded and, if it contains
+    /// non-ASCII characters, encoded as a Punycode `xn--` A-label
+    /// (RFC3492) so the result is plain ASCII on the wire.
+    ///
+    /// This applies only the case-folding step of UTS-46, not full
+    /// nameprep normalization (NFC, combining-mark reordering, etc) —
+    /// callers passing already-normalized Unicode get correct results.
+    pub fn from_unicode(text: &str) -> Result<Name<'static>, Error> {
+        if text.is_empty() || text == "." {
+            return Ok(Name { labels: Vec::new() });
+        }
+        let mut labels = Vec::new();
+        for raw_label in split_escaped(text)? {
+            let text_label = String::from_utf8(raw_label).map_err(|_| Error::InvalidIdnaLabel)?.to_lowercase();
+            let encoded = if text_label.is_ascii() {
+                text_label.into_bytes()
+            } else {
+                let mut alabel = String::from("xn--");
+                alabel.push_str(&crate::punycode::encode(&text_label)?);
+                alabel.into_bytes()
+            };
+            Name::push_label(&mut labels, encoded)?;
+        }
+        let wire_len: usize = labels.iter().map(|l| l.len() + 1).sum::<usize>() + 1;
+        if wire_len > 255 {
+            return Err(Error::NameTooLong);
+        }
+        Ok(Name { labels })
+    }
+
+    /// Render this name back to its Unicode presentation form, decoding
+    /// any `xn--` labels (the inverse of [`from_unicode`](Name::from_unicode)).
+    /// Labels that aren't valid Punycode, or whose bytes aren't valid
+    /// UTF-8, are left as-is rather than failing the whole name.
+    pub fn to_unicode(&self) -> String {
+        if self.is_root() {
+            return ".".to_string();
+        }
+        let mut out = String::new();
+        for label in &self.labels {
+            let text = String::from_utf8_lossy(label);
+            let decoded = text
+                .strip_prefix("xn--")
+                .or_else(|| text.strip_prefix("XN--"))
+                .and_then(|punycode| crate::punycode::decode(punycode).ok());
+            out.push_str(decoded.as_deref().unwrap_or(&text));
+            out.push('.');
+        }
+        out
+    }
+}
+
+/// Split dotted presentation-format text into its raw (not yet
+/// length-checked) label byte strings: `\.` escapes a literal dot inside
+/// a label and `\DDD` escapes an arbitrary byte by decimal value. Shared
+/// by [`FromStr`] and [`Name::from_unicode`].
+fn split_escaped(text: &str) -> Result<Vec<Vec<u8>>, Error> {
+    let mut labels = Vec::new();
+    let mut label = Vec::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next().ok_or(Error::UnknownLabelFormat)? {
+                d if d.is_ascii_digit() => {
+                    let value = [d, chars.next().ok_or(Error::UnknownLabelFormat)?, chars.next().ok_or(Error::UnknownLabelFormat)?]
+                        .iter()
+                        .try_fold(0u32, |acc, c| Some(acc * 10 + c.to_digit(10)?))
+                        .ok_or(Error::UnknownLabelFormat)?;
+                    label.push(u8::try_from(value).map_err(|_| Error::UnknownLabelFormat)?);
+                }
+                other => {
+                    let mut buf = [0u8; 4];
+                    label.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+                }
+            },
+            '.' => labels.push(std::mem::take(&mut label)),
+            c => {
+                let mut buf = [0u8; 4];
+                label.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+        }
+    }
+    if !label.is_empty() {
+        labels.push(label);
+    }
+    Ok(labels)
+}
+
+impl FromStr for Name<'static> {
+    type Err = Error;
+
+    /// Parse dotted presentation-format text (the same format [`Display`]
+    /// produces) into an owned name: `\.` escapes a literal dot inside a
+    /// label and `\DDD` escapes an arbitrary byte by decimal value. The
+    /// root name is written as `.` or the empty string.
+    fn from_str(text: &str) -> Result<Name<'static>, Error> {
+        if text.is_empty() || text == "." {
+            return Ok(Name { labels: Vec::new() });
+        }
+        let mut labels = Vec::new();
+        for raw_label in split_escaped(text)? {
+            Name::push_label(&mut labels, raw_label)?;
+        }
+        let wire_len: usize = labels.iter().map(|l| l.len() + 1).sum::<usize>() + 1;
+        if wire_len > 255 {
+            return Err(Error::NameTooLong);
+        }
+        Ok(Name { labels })
+    }
+}
+
+impl<'a> fmt::Display for Name<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_root() {
+            return write!(f, ".");
+        }
+        for label in &self.labels {
+            for &b in label.as_ref() {
+                match b {
+                    b'.' | b'\\' => write!(f, "\\{}", b as char)?,
+                    0x21..=0x7e => write!(f, "{}", b as char)?,
+                    _ => write!(f, "\\{:03}", b)?,
+                }
+            }
+            write!(f, ".")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for Name<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Name({:?})", self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Name;
+
+    #[test]
+    fn from_str_and_display_round_trip() {
+        let name = Name::from_str("example.com").unwrap();
+        assert_eq!(name.to_string(), "example.com.");
+    }
+
+    #[test]
+    fn from_str_accepts_trailing_dot_and_root() {
+        assert_eq!(Name::from_str("example.com.").unwrap().to_string(), "example.com.");
+        assert!(Name::from_str(".").unwrap().is_root());
+        assert!(Name::from_str("").unwrap().is_root());
+    }
+
+    #[test]
+    fn escaped_dot_stays_inside_one_label() {
+        let name = Name::from_str("a\\.b.com").unwrap();
+        let labels: Vec<&[u8]> = name.labels().collect();
+        assert_eq!(labels, vec![b"a.b".as_slice(), b"com".as_slice()]);
+        assert_eq!(name.to_string(), "a\\.b.com.");
+    }
+
+    #[test]
+    fn decimal_escape_decodes_arbitrary_byte() {
+        let name = Name::from_str("a\\007b.com").unwrap();
+        let labels: Vec<&[u8]> = name.labels().collect();
+        assert_eq!(labels[0], b"a\x07b");
+    }
+
+    #[test]
+    fn display_escapes_non_printable_bytes() {
+        let name = Name::from_str("a\\007b.com").unwrap();
+        assert_eq!(name.to_string(), "a\\007b.com.");
+    }
+
+    #[test]
+    fn rejects_labels_over_63_bytes() {
+        let label = "a".repeat(64);
+        assert!(Name::from_str(&format!("{}.com", label)).is_err());
+    }
+
+    #[test]
+    fn from_unicode_punycode_encodes_non_ascii_labels() {
+        // RFC3492's own worked example: "münchen" <-> "mnchen-3ya"
+        let name = Name::from_unicode("münchen.de").unwrap();
+        let labels: Vec<&[u8]> = name.labels().collect();
+        assert_eq!(labels[0], b"xn--mnchen-3ya");
+        assert_eq!(labels[1], b"de");
+    }
+
+    #[test]
+    fn to_unicode_decodes_punycode_labels() {
+        let name = Name::from_unicode("münchen.de").unwrap();
+        assert_eq!(name.to_unicode(), "münchen.de.");
+    }
+
+    #[test]
+    fn from_unicode_lowercases_ascii_labels() {
+        let name = Name::from_unicode("WWW.Example.com").unwrap();
+        assert_eq!(name.to_string(), "www.example.com.");
+    }
+}
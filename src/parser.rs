@@ -0,0 +1,140 @@
+use std::convert::TryInto;
+
+use crate::rdata::{self, QueryType, RData, Record as _, Type};
+use crate::structs::{Packet, Question, ResourceRecord};
+use crate::{Class, Error, Header, Name, QueryClass};
+
+impl<'a> Packet<'a> {
+    /// Parse a complete DNS packet
+    pub fn parse(data: &'a [u8]) -> Result<Packet<'a>, Error> {
+        let header = Header::parse(data)?;
+        let mut pos = Header::size();
+
+        let mut questions = Vec::with_capacity(header.questions as usize);
+        for _ in 0..header.questions {
+            let (question, next) = parse_question(data, pos)?;
+            questions.push(question);
+            pos = next;
+        }
+
+        let mut answers = Vec::with_capacity(header.answers as usize);
+        for _ in 0..header.answers {
+            let (rr, next) = parse_resource_record(data, pos)?;
+            answers.push(rr);
+            pos = next;
+        }
+
+        let mut nameservers = Vec::with_capacity(header.nameservers as usize);
+        for _ in 0..header.nameservers {
+            let (rr, next) = parse_resource_record(data, pos)?;
+            nameservers.push(rr);
+            pos = next;
+        }
+
+        let mut additional = Vec::with_capacity(header.additional as usize);
+        for _ in 0..header.additional {
+            let (rr, next) = parse_resource_record(data, pos)?;
+            additional.push(rr);
+            pos = next;
+        }
+
+        Ok(Packet { header, questions, answers, nameservers, additional })
+    }
+}
+
+fn parse_question(data: &[u8], pos: usize) -> Result<(Question<'_>, usize), Error> {
+    let (qname, pos) = Name::parse(data, pos)?;
+    let tail: [u8; 4] = data.get(pos..pos + 4).ok_or(Error::PacketTooShort)?.try_into().unwrap();
+    let qtype = u16::from_be_bytes(tail[0..2].try_into().unwrap());
+    let qclass = u16::from_be_bytes(tail[2..4].try_into().unwrap());
+    Ok((
+        Question {
+            qname,
+            prefer_unicast: qclass & 0x8000 != 0,
+            qtype: QueryType::from(qtype),
+            qclass: QueryClass::from(qclass & 0x7fff),
+        },
+        pos + 4,
+    ))
+}
+
+fn parse_resource_record(data: &[u8], pos: usize) -> Result<(ResourceRecord<'_>, usize), Error> {
+    let (name, pos) = Name::parse(data, pos)?;
+    let head: [u8; 10] = data.get(pos..pos + 10).ok_or(Error::PacketTooShort)?.try_into().unwrap();
+    let type_code = u16::from_be_bytes(head[0..2].try_into().unwrap());
+    let class_code = u16::from_be_bytes(head[2..4].try_into().unwrap());
+    let ttl = u32::from_be_bytes(head[4..8].try_into().unwrap());
+    let rdlength = u16::from_be_bytes(head[8..10].try_into().unwrap()) as usize;
+    let rdata_start = pos + 10;
+    let rdata = data
+        .get(rdata_start..rdata_start + rdlength)
+        .ok_or(Error::PacketTooShort)?;
+    let next = rdata_start + rdlength;
+
+    // EDNS0 (RFC6891) repurposes CLASS/TTL, so OPT can't go through the
+    // normal `Record::parse` path, which assumes a real class and a
+    // plain TTL.
+    if Type::from(type_code) == Type::OPT {
+        let data = RData::OPT(rdata::opt::Record::parse(class_code, ttl, rdata));
+        return Ok((
+            ResourceRecord { name, multicast_unique: false, cls: Class::IN, ttl, data },
+            next,
+        ));
+    }
+
+    let cls = Class::from(class_code & 0x7fff);
+    let data = parse_rdata(type_code, rdata, data)?;
+    Ok((
+        ResourceRecord { name, multicast_unique: class_code & 0x8000 != 0, cls, ttl, data },
+        next,
+    ))
+}
+
+fn parse_rdata<'a>(type_code: u16, rdata: &'a [u8], original: &'a [u8]) -> Result<RData<'a>, Error> {
+    use crate::rdata::{A, Aaaa, Cname, Dnskey, Ds, Mx, Ns, Nsec, Nsec3, Ptr, Rrsig, Soa, Srv, Txt};
+    match Type::from(type_code) {
+        Type::A => A::parse(rdata, original),
+        Type::AAAA => Aaaa::parse(rdata, original),
+        Type::CNAME => Cname::parse(rdata, original),
+        Type::MX => Mx::parse(rdata, original),
+        Type::NS => Ns::parse(rdata, original),
+        Type::PTR => Ptr::parse(rdata, original),
+        Type::SOA => Soa::parse(rdata, original),
+        Type::SRV => Srv::parse(rdata, original),
+        Type::TXT => Txt::parse(rdata, original),
+        Type::DNSKEY => Dnskey::parse(rdata, original),
+        Type::RRSIG => Rrsig::parse(rdata, original),
+        Type::DS => Ds::parse(rdata, original),
+        Type::NSEC => Nsec::parse(rdata, original),
+        Type::NSEC3 => Nsec3::parse(rdata, original),
+        Type::OPT => unreachable!("OPT is handled in parse_resource_record"),
+        // RFC3597: don't drop a record just because we don't model its
+        // TYPE, keep the raw RDATA around instead.
+        Type::Unknown(code) => Ok(RData::Unknown { type_code: code, data: rdata }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rdata::RData;
+    use crate::structs::Packet;
+
+    #[test]
+    fn unrecognized_type_falls_back_to_unknown_rdata() {
+        // header: id=0, flags=0, 0 questions, 1 answer, 0 authority, 0 additional
+        let mut packet = b"\x00\x00\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00".to_vec();
+        // answer: root name, TYPE 65280 (private-use, unmodeled), CLASS IN,
+        // TTL 0, RDLENGTH 2, RDATA 0xabcd
+        packet.extend_from_slice(b"\x00\xff\x00\x00\x01\x00\x00\x00\x00\x00\x02\xab\xcd");
+
+        let parsed = Packet::parse(&packet).unwrap();
+        assert_eq!(parsed.answers.len(), 1);
+        match parsed.answers[0].data {
+            RData::Unknown { type_code, data } => {
+                assert_eq!(type_code, 65280);
+                assert_eq!(data, b"\xab\xcd");
+            }
+            ref other => panic!("expected Unknown, got {:?}", other),
+        }
+    }
+}
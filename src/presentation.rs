@@ -0,0 +1,124 @@
+//! Helpers for rendering binary rdata in master-file (zone file)
+//! presentation format: base64 for signature/key blobs, hex for
+//! digest/salt blobs, following common zone-file convention.
+
+use crate::Error;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn base64(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let n = (b0 << 16) | (b1 << 8) | b2;
+        out.push(BASE64_ALPHABET[(n >> 18 & 0x3f) as usize] as char);
+        out.push(BASE64_ALPHABET[(n >> 12 & 0x3f) as usize] as char);
+        out.push(if chunk.len() > 1 { BASE64_ALPHABET[(n >> 6 & 0x3f) as usize] as char } else { '=' });
+        out.push(if chunk.len() > 2 { BASE64_ALPHABET[(n & 0x3f) as usize] as char } else { '=' });
+    }
+    out
+}
+
+pub(crate) fn hex(data: &[u8]) -> String {
+    use std::fmt::Write;
+    let mut out = String::with_capacity(data.len() * 2);
+    for b in data {
+        write!(out, "{:02x}", b).unwrap();
+    }
+    out
+}
+
+fn hex_decode(text: &str) -> Result<Vec<u8>, Error> {
+    let digits: Vec<u8> = text
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .map(|b| (b as char).to_digit(16).map(|d| d as u8).ok_or(Error::UnknownLabelFormat))
+        .collect::<Result<_, _>>()?;
+    if digits.len() % 2 != 0 {
+        return Err(Error::UnknownLabelFormat);
+    }
+    Ok(digits.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect())
+}
+
+/// Parse the RFC3597 `\# <length> <hex>` generic RDATA representation
+/// back into raw bytes, the inverse of how [`RData::Unknown`][crate::RData]
+/// is displayed. Whitespace is permitted inside the hex run.
+pub(crate) fn parse_generic_rdata(text: &str) -> Result<Vec<u8>, Error> {
+    let text = text.trim();
+    let rest = text.strip_prefix("\\#").ok_or(Error::UnknownLabelFormat)?.trim_start();
+    let (length, hex_digits) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let length: usize = length.parse().map_err(|_| Error::UnknownLabelFormat)?;
+    let data = hex_decode(hex_digits.trim())?;
+    if data.len() != length {
+        return Err(Error::WrongRdataLength);
+    }
+    Ok(data)
+}
+
+/// Quote and escape a character-string the way zone files expect TXT
+/// data to be written: wrapped in `"`, with `"` and `\` backslash-escaped.
+pub(crate) fn quoted_string(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len() + 2);
+    out.push('"');
+    for &b in data {
+        match b {
+            b'"' | b'\\' => {
+                out.push('\\');
+                out.push(b as char);
+            }
+            0x20..=0x7e => out.push(b as char),
+            _ => out.push_str(&format!("\\{:03}", b)),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{base64, hex, parse_generic_rdata, quoted_string};
+    use crate::Error;
+
+    #[test]
+    fn base64_matches_rfc4648_test_vectors() {
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foob"), "Zm9vYg==");
+        assert_eq!(base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn hex_lowercases_and_pads() {
+        assert_eq!(hex(&[0x00, 0xab, 0xcd, 0xef]), "00abcdef");
+    }
+
+    #[test]
+    fn parse_generic_rdata_round_trips_hex() {
+        assert_eq!(parse_generic_rdata("\\# 3 abcdef").unwrap(), vec![0xab, 0xcd, 0xef]);
+    }
+
+    #[test]
+    fn parse_generic_rdata_rejects_length_mismatch() {
+        assert!(matches!(parse_generic_rdata("\\# 2 abcdef"), Err(Error::WrongRdataLength)));
+    }
+
+    #[test]
+    fn parse_generic_rdata_rejects_missing_marker() {
+        assert!(matches!(parse_generic_rdata("3 abcdef"), Err(Error::UnknownLabelFormat)));
+    }
+
+    #[test]
+    fn quoted_string_escapes_quotes_and_backslashes() {
+        assert_eq!(quoted_string(b"a\"b\\c"), "\"a\\\"b\\\\c\"");
+    }
+
+    #[test]
+    fn quoted_string_escapes_non_printable_bytes() {
+        assert_eq!(quoted_string(&[0x07]), "\"\\007\"");
+    }
+}
@@ -0,0 +1,162 @@
+//! Bootstring/Punycode encoding (RFC3492), used by the `name` module to
+//! turn a Unicode label into the ASCII `xn--` form that goes on the wire
+//! and back.
+
+use crate::Error;
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+}
+
+fn digit_to_char(digit: u32) -> u8 {
+    match digit {
+        0..=25 => b'a' + digit as u8,
+        26..=35 => b'0' + (digit - 26) as u8,
+        _ => unreachable!("punycode digits are always in 0..36"),
+    }
+}
+
+fn char_to_digit(c: u8) -> Option<u32> {
+    match c {
+        b'a'..=b'z' => Some((c - b'a') as u32),
+        b'A'..=b'Z' => Some((c - b'A') as u32),
+        b'0'..=b'9' => Some((c - b'0') as u32 + 26),
+        _ => None,
+    }
+}
+
+/// Encode a single Unicode label into its Punycode form (without the
+/// `xn--` prefix).
+pub(crate) fn encode(label: &str) -> Result<String, Error> {
+    let mut out = String::new();
+    let basic: Vec<char> = label.chars().filter(char::is_ascii).collect();
+    let input: Vec<u32> = label.chars().map(|c| c as u32).collect();
+
+    for &c in &basic {
+        out.push(c);
+    }
+    let mut h = basic.len();
+    let b = basic.len();
+    if b > 0 {
+        out.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    while h < input.len() {
+        let m = *input.iter().filter(|&&c| c >= n).min().ok_or(Error::InvalidIdnaLabel)?;
+        delta = delta.checked_add((m - n).checked_mul(h as u32 + 1).ok_or(Error::InvalidIdnaLabel)?).ok_or(Error::InvalidIdnaLabel)?;
+        n = m;
+
+        for &c in &input {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(Error::InvalidIdnaLabel)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = if k <= bias { TMIN } else { (k - bias).min(TMAX) };
+                    if q < t {
+                        break;
+                    }
+                    out.push(digit_to_char(t + (q - t) % (BASE - t)) as char);
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                out.push(digit_to_char(q) as char);
+                bias = adapt(delta, h as u32 + 1, h == b);
+                delta = 0;
+                h += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(out)
+}
+
+/// Decode a Punycode label (without the `xn--` prefix) back to Unicode.
+pub(crate) fn decode(input: &str) -> Result<String, Error> {
+    let input = input.as_bytes();
+    let split = input.iter().rposition(|&b| b == b'-');
+    let (basic, rest) = match split {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => (&input[..0], input),
+    };
+
+    let mut output: Vec<u32> = basic.iter().map(|&b| b as u32).collect();
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut pos = 0;
+
+    while pos < rest.len() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = char_to_digit(*rest.get(pos).ok_or(Error::InvalidIdnaLabel)?).ok_or(Error::InvalidIdnaLabel)?;
+            pos += 1;
+            i = i.checked_add(digit.checked_mul(w).ok_or(Error::InvalidIdnaLabel)?).ok_or(Error::InvalidIdnaLabel)?;
+            let t = if k <= bias { TMIN } else { (k - bias).min(TMAX) };
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t).ok_or(Error::InvalidIdnaLabel)?;
+            k += BASE;
+        }
+        bias = adapt(i - old_i, output.len() as u32 + 1, old_i == 0);
+        n = n.checked_add(i / (output.len() as u32 + 1)).ok_or(Error::InvalidIdnaLabel)?;
+        i %= output.len() as u32 + 1;
+        output.insert(i as usize, n);
+        i += 1;
+    }
+
+    output.into_iter().map(|c| char::from_u32(c).ok_or(Error::InvalidIdnaLabel)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, encode};
+
+    // RFC3492 §7.1's own worked examples (minus the "xn--" prefix, which
+    // is added by the `name` module, not this one).
+    const VECTORS: &[(&str, &str)] = &[("münchen", "mnchen-3ya"), ("ü", "tda")];
+
+    #[test]
+    fn encode_matches_rfc3492_vectors() {
+        for &(unicode, ascii) in VECTORS {
+            assert_eq!(encode(unicode).unwrap(), ascii, "encoding {:?}", unicode);
+        }
+    }
+
+    #[test]
+    fn decode_matches_rfc3492_vectors() {
+        for &(unicode, ascii) in VECTORS {
+            assert_eq!(decode(ascii).unwrap(), unicode, "decoding {:?}", ascii);
+        }
+    }
+
+    #[test]
+    fn encode_is_identity_for_pure_ascii() {
+        assert_eq!(encode("abc").unwrap(), "abc-");
+    }
+}
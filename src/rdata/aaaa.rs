@@ -0,0 +1,17 @@
+use std::{convert::TryInto, net::Ipv6Addr};
+
+use crate::Error;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Record(pub Ipv6Addr);
+
+impl<'a> super::Record<'a> for Record {
+    const TYPE: isize = 28;
+
+    fn parse(rdata: &'a [u8], _original: &'a [u8]) -> super::RDataResult<'a> {
+        let rdata: [u8; 16] = rdata.try_into().map_err(|_| Error::WrongRdataLength)?;
+        let address = Ipv6Addr::from(rdata);
+        let record = Record(address);
+        Ok(super::RData::AAAA(record))
+    }
+}
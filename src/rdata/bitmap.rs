@@ -0,0 +1,97 @@
+use super::Type;
+
+/// Encode a list of types into an RFC4034 §4.1.2 type bitmap, the
+/// inverse of [`decode`]. `types` need not be sorted or deduplicated.
+pub(crate) fn encode(types: &[Type]) -> Vec<u8> {
+    let mut by_window: Vec<(u16, [u8; 32])> = Vec::new();
+    for &ty in types {
+        let code: u16 = ty.into();
+        let window = code / 256;
+        let bit = (code % 256) as usize;
+        let entry = match by_window.iter_mut().find(|(w, _)| *w == window) {
+            Some(entry) => entry,
+            None => {
+                by_window.push((window, [0u8; 32]));
+                by_window.last_mut().unwrap()
+            }
+        };
+        entry.1[bit / 8] |= 0x80 >> (bit % 8);
+    }
+    by_window.sort_by_key(|(w, _)| *w);
+
+    let mut out = Vec::new();
+    for (window, bitmap) in by_window {
+        let len = bitmap.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        if len == 0 {
+            continue;
+        }
+        out.push(window as u8);
+        out.push(len as u8);
+        out.extend_from_slice(&bitmap[..len]);
+    }
+    out
+}
+
+/// Decode an RFC4034 §4.1.2 type bitmap: a sequence of
+/// `{window-number, bitmap-length, bitmap}` blocks, where bit `N` of
+/// window `W` means type `256 * W + N` is present.
+pub(crate) fn decode(mut data: &[u8]) -> Vec<Type> {
+    let mut types = Vec::new();
+    while data.len() >= 2 {
+        let window = data[0] as u16;
+        let len = data[1] as usize;
+        let bitmap = match data.get(2..2 + len) {
+            Some(b) => b,
+            None => break,
+        };
+        for (byte_index, &byte) in bitmap.iter().enumerate() {
+            for bit in 0..8 {
+                if byte & (0x80 >> bit) != 0 {
+                    let type_code = window * 256 + (byte_index * 8 + bit) as u16;
+                    types.push(Type::from(type_code));
+                }
+            }
+        }
+        data = &data[2 + len..];
+    }
+    types
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, encode};
+    use crate::rdata::Type;
+
+    #[test]
+    fn round_trips_types_in_the_same_window() {
+        let types = vec![Type::A, Type::NS, Type::SOA, Type::RRSIG, Type::NSEC];
+        let encoded = encode(&types);
+        let mut decoded = decode(&encoded);
+        let mut expected = types;
+        decoded.sort_by_key(|t| u16::from(*t));
+        expected.sort_by_key(|t| u16::from(*t));
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn round_trips_types_across_windows() {
+        // DNSKEY (48) is in window 0, a type like TYPE65280 is in window 255
+        let types = vec![Type::DNSKEY, Type::from(65280)];
+        let encoded = encode(&types);
+        let decoded = decode(&encoded);
+        assert_eq!(decoded, types);
+    }
+
+    #[test]
+    fn decode_empty_bitmap_is_empty() {
+        assert_eq!(decode(&[]), Vec::<Type>::new());
+    }
+
+    #[test]
+    fn encode_matches_rfc4034_example() {
+        // RFC4034 §4.1.2 example NSEC bitmap for A, MX, RRSIG, NSEC
+        let types = vec![Type::A, Type::MX, Type::RRSIG, Type::NSEC];
+        let encoded = encode(&types);
+        assert_eq!(encoded, vec![0x00, 0x06, 0x40, 0x01, 0x00, 0x00, 0x00, 0x03]);
+    }
+}
@@ -0,0 +1,14 @@
+use crate::Name;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a>(pub Name<'a>);
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 5;
+
+    fn parse(rdata: &'a [u8], original: &'a [u8]) -> super::RDataResult<'a> {
+        let offset = rdata.as_ptr() as usize - original.as_ptr() as usize;
+        let (name, _) = Name::parse(original, offset)?;
+        Ok(super::RData::CNAME(Record(name)))
+    }
+}
@@ -1,3 +1,6 @@
+use std::fmt;
+
+use crate::presentation::{base64, hex, quoted_string};
 use crate::rdata::*;
 
 /// The enumeration that represents implemented types of DNS resource records data
@@ -12,5 +15,130 @@ pub enum RData<'a> {
     SOA(Soa<'a>),
     SRV(Srv<'a>),
     TXT(Txt<'a>),
-    OPT(&'a [u8]),
+    OPT(opt::Record<'a>),
+    DNSKEY(Dnskey<'a>),
+    RRSIG(Rrsig<'a>),
+    DS(Ds<'a>),
+    NSEC(Nsec<'a>),
+    NSEC3(Nsec3<'a>),
+    /// RFC3597 fallback for any TYPE this crate doesn't model, e.g. CAA,
+    /// HTTPS/SVCB or TLSA. Keeps the raw RDATA instead of dropping the
+    /// record.
+    Unknown {
+        type_code: u16,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> RData<'a> {
+    /// The wire TYPE value of the record this RDATA belongs to
+    pub fn type_code(&self) -> Type {
+        use self::RData::*;
+        match self {
+            A(..) => Type::A,
+            AAAA(..) => Type::AAAA,
+            CNAME(..) => Type::CNAME,
+            MX(..) => Type::MX,
+            NS(..) => Type::NS,
+            PTR(..) => Type::PTR,
+            SOA(..) => Type::SOA,
+            SRV(..) => Type::SRV,
+            TXT(..) => Type::TXT,
+            OPT(..) => Type::OPT,
+            DNSKEY(..) => Type::DNSKEY,
+            RRSIG(..) => Type::RRSIG,
+            DS(..) => Type::DS,
+            NSEC(..) => Type::NSEC,
+            NSEC3(..) => Type::NSEC3,
+            Unknown { type_code, .. } => Type::from(*type_code),
+        }
+    }
+}
+
+impl<'a> fmt::Display for RData<'a> {
+    /// Render the RDATA the way it would appear after the TYPE in a
+    /// master-file (zone file) record line.
+    ///
+    /// Fields with no textual schema follow zone-file convention:
+    /// base64 for signature/key blobs, hex for digest/salt blobs.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::RData::*;
+        match self {
+            A(r) => write!(f, "{}", r.0),
+            AAAA(r) => write!(f, "{}", r.0),
+            CNAME(r) => write!(f, "{}", r.0),
+            NS(r) => write!(f, "{}", r.0),
+            PTR(r) => write!(f, "{}", r.0),
+            MX(r) => write!(f, "{} {}", r.preference, r.exchange),
+            SRV(r) => write!(f, "{} {} {} {}", r.priority, r.weight, r.port, r.target),
+            SOA(r) => write!(
+                f,
+                "{} {} {} {} {} {} {}",
+                r.primary_ns, r.mailbox, r.serial, r.refresh, r.retry, r.expire, r.minimum_ttl
+            ),
+            TXT(r) => {
+                let strings: Vec<String> = r.0.iter().map(|s| quoted_string(s)).collect();
+                write!(f, "{}", strings.join(" "))
+            }
+            OPT(r) => write!(
+                f,
+                "; EDNS: version: {}, udp={}, flags:{}",
+                r.version,
+                r.udp_payload_size,
+                if r.dnssec_ok { " do" } else { "" }
+            ),
+            DNSKEY(r) => write!(f, "{} {} {} {}", r.flags, r.protocol, r.algorithm, base64(r.public_key)),
+            DS(r) => write!(f, "{} {} {} {}", r.key_tag, r.algorithm, r.digest_type, hex(r.digest)),
+            RRSIG(r) => write!(
+                f,
+                "{} {} {} {} {} {} {} {} {}",
+                r.type_covered,
+                r.algorithm,
+                r.labels,
+                r.original_ttl,
+                r.signature_expiration,
+                r.signature_inception,
+                r.key_tag,
+                r.signers_name,
+                base64(r.signature)
+            ),
+            NSEC(r) => {
+                let types: Vec<String> = r.types.iter().map(Type::to_string).collect();
+                write!(f, "{} {}", r.next_domain_name, types.join(" "))
+            }
+            NSEC3(r) => {
+                let types: Vec<String> = r.types.iter().map(Type::to_string).collect();
+                write!(
+                    f,
+                    "{} {} {} {} {} {}",
+                    r.hash_algorithm,
+                    r.flags,
+                    r.iterations,
+                    hex(r.salt),
+                    hex(r.next_hashed_owner_name),
+                    types.join(" ")
+                )
+            }
+            // RFC3597 generic representation: `\# <length> <hex>`
+            Unknown { data, .. } => write!(f, "\\# {} {}", data.len(), hex(data)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RData;
+    use crate::rdata::Type;
+
+    #[test]
+    fn unknown_type_code_resolves_through_type_from() {
+        let rdata = RData::Unknown { type_code: 65280, data: b"\xab\xcd" };
+        assert_eq!(rdata.type_code(), Type::from(65280u16));
+    }
+
+    #[test]
+    fn unknown_displays_as_rfc3597_generic_representation() {
+        let rdata = RData::Unknown { type_code: 65280, data: b"\xab\xcd" };
+        assert_eq!(rdata.to_string(), "\\# 2 abcd");
+    }
 }
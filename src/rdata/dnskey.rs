@@ -0,0 +1,54 @@
+use std::convert::TryInto;
+
+use crate::Error;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub flags: u16,
+    pub protocol: u8,
+    pub algorithm: u8,
+    pub public_key: &'a [u8],
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 48;
+
+    fn parse(rdata: &'a [u8], _original: &'a [u8]) -> super::RDataResult<'a> {
+        if rdata.len() < 4 {
+            return Err(Error::WrongRdataLength);
+        }
+        Ok(super::RData::DNSKEY(Record {
+            flags: u16::from_be_bytes(rdata[0..2].try_into().unwrap()),
+            protocol: rdata[2],
+            algorithm: rdata[3],
+            public_key: &rdata[4..],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Record;
+    use crate::rdata::{RData, Record as _};
+    use crate::Error;
+
+    #[test]
+    fn parse_zone_signing_key() {
+        let rdata = b"\x01\x00\x03\x08\xab\xcd\xef";
+        match Record::parse(rdata, rdata).unwrap() {
+            RData::DNSKEY(r) => {
+                assert_eq!(r.flags, 0x0100);
+                assert_eq!(r.protocol, 3);
+                assert_eq!(r.algorithm, 8);
+                assert_eq!(r.public_key, b"\xab\xcd\xef");
+            }
+            other => panic!("expected DNSKEY, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_short_rdata() {
+        let rdata = b"\x01\x00\x03";
+        assert!(matches!(Record::parse(rdata, rdata), Err(Error::WrongRdataLength)));
+    }
+}
@@ -0,0 +1,54 @@
+use std::convert::TryInto;
+
+use crate::Error;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub key_tag: u16,
+    pub algorithm: u8,
+    pub digest_type: u8,
+    pub digest: &'a [u8],
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 43;
+
+    fn parse(rdata: &'a [u8], _original: &'a [u8]) -> super::RDataResult<'a> {
+        if rdata.len() < 4 {
+            return Err(Error::WrongRdataLength);
+        }
+        Ok(super::RData::DS(Record {
+            key_tag: u16::from_be_bytes(rdata[0..2].try_into().unwrap()),
+            algorithm: rdata[2],
+            digest_type: rdata[3],
+            digest: &rdata[4..],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Record;
+    use crate::rdata::{RData, Record as _};
+    use crate::Error;
+
+    #[test]
+    fn parse_delegation_signer() {
+        let rdata = b"\x30\x39\x08\x02\xab\xcd\xef\x01";
+        match Record::parse(rdata, rdata).unwrap() {
+            RData::DS(r) => {
+                assert_eq!(r.key_tag, 12345);
+                assert_eq!(r.algorithm, 8);
+                assert_eq!(r.digest_type, 2);
+                assert_eq!(r.digest, b"\xab\xcd\xef\x01");
+            }
+            other => panic!("expected DS, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_short_rdata() {
+        let rdata = b"\x30\x39\x08";
+        assert!(matches!(Record::parse(rdata, rdata), Err(Error::WrongRdataLength)));
+    }
+}
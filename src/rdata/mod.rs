@@ -0,0 +1,176 @@
+//! Data types and methods for handling the RData field
+
+use crate::Error;
+
+mod a;
+mod aaaa;
+pub(crate) mod bitmap;
+mod cname;
+mod config;
+mod dnskey;
+mod ds;
+mod mx;
+mod ns;
+mod nsec;
+mod nsec3;
+pub mod opt;
+mod ptr;
+mod rrsig;
+mod soa;
+mod srv;
+mod txt;
+
+pub use self::a::Record as A;
+pub use self::aaaa::Record as Aaaa;
+pub use self::cname::Record as Cname;
+pub use self::config::RData;
+pub use self::dnskey::Record as Dnskey;
+pub use self::ds::Record as Ds;
+pub use self::mx::Record as Mx;
+pub use self::ns::Record as Ns;
+pub use self::nsec::Record as Nsec;
+pub use self::nsec3::Record as Nsec3;
+pub use self::ptr::Record as Ptr;
+pub use self::rrsig::Record as Rrsig;
+pub use self::soa::Record as Soa;
+pub use self::srv::Record as Srv;
+pub use self::txt::Record as Txt;
+
+/// Result of parsing the RDATA section of a resource record
+pub type RDataResult<'a> = Result<RData<'a>, Error>;
+
+/// Implemented by every RDATA type that can be parsed out of a resource
+/// record whose wire format doesn't need anything beyond its own bytes
+/// (i.e. everything except OPT, which repurposes the CLASS/TTL fields)
+pub trait Record<'a>: Sized {
+    /// The TYPE value this record is parsed from
+    const TYPE: isize;
+
+    /// Parse the RDATA bytes of the record. `original` is the whole
+    /// packet, needed by record types whose RDATA contains a (possibly
+    /// compressed) name.
+    fn parse(rdata: &'a [u8], original: &'a [u8]) -> RDataResult<'a>;
+}
+
+/// Defines a numeric enum that round-trips through `u16`, with a named
+/// variant for every value the spec gives a name to and an `Unknown(u16)`
+/// fallback for everything else (RFC3597), instead of erroring out on an
+/// unrecognized numeric value.
+macro_rules! enum_with_unknown {
+    ($(#[$meta:meta])* pub enum $name:ident { $($variant:ident = $value:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        pub enum $name {
+            $($variant,)*
+            /// A value this parser doesn't have a named variant for
+            Unknown(u16),
+        }
+
+        impl From<u16> for $name {
+            fn from(value: u16) -> $name {
+                match value {
+                    $($value => $name::$variant,)*
+                    x => $name::Unknown(x),
+                }
+            }
+        }
+
+        impl From<$name> for u16 {
+            fn from(value: $name) -> u16 {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(x) => x,
+                }
+            }
+        }
+    }
+}
+
+enum_with_unknown! {
+    /// The TYPE field of a resource record
+    pub enum Type {
+        A = 1,
+        NS = 2,
+        CNAME = 5,
+        SOA = 6,
+        PTR = 12,
+        MX = 15,
+        TXT = 16,
+        AAAA = 28,
+        SRV = 33,
+        OPT = 41,
+        DS = 43,
+        RRSIG = 46,
+        NSEC = 47,
+        DNSKEY = 48,
+        NSEC3 = 50,
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use self::Type::*;
+        match self {
+            A => f.write_str("A"),
+            AAAA => f.write_str("AAAA"),
+            CNAME => f.write_str("CNAME"),
+            MX => f.write_str("MX"),
+            NS => f.write_str("NS"),
+            OPT => f.write_str("OPT"),
+            PTR => f.write_str("PTR"),
+            SOA => f.write_str("SOA"),
+            SRV => f.write_str("SRV"),
+            TXT => f.write_str("TXT"),
+            DS => f.write_str("DS"),
+            RRSIG => f.write_str("RRSIG"),
+            NSEC => f.write_str("NSEC"),
+            DNSKEY => f.write_str("DNSKEY"),
+            NSEC3 => f.write_str("NSEC3"),
+            Unknown(code) => write!(f, "TYPE{}", code),
+        }
+    }
+}
+
+enum_with_unknown! {
+    /// The TYPE field of a question, which additionally allows
+    /// meta-types like `AXFR` and `*` (All)
+    pub enum QueryType {
+        A = 1,
+        NS = 2,
+        CNAME = 5,
+        SOA = 6,
+        PTR = 12,
+        MX = 15,
+        TXT = 16,
+        AAAA = 28,
+        SRV = 33,
+        OPT = 41,
+        AXFR = 252,
+        All = 255,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{QueryType, Type};
+
+    #[test]
+    fn type_round_trips_known_values() {
+        assert_eq!(Type::from(1u16), Type::A);
+        assert_eq!(u16::from(Type::A), 1);
+    }
+
+    #[test]
+    fn type_falls_back_to_unknown_instead_of_panicking() {
+        assert_eq!(Type::from(65280u16), Type::Unknown(65280));
+        assert_eq!(u16::from(Type::Unknown(65280)), 65280);
+        assert_eq!(Type::Unknown(65280).to_string(), "TYPE65280");
+    }
+
+    #[test]
+    fn query_type_round_trips_meta_types() {
+        assert_eq!(QueryType::from(252u16), QueryType::AXFR);
+        assert_eq!(QueryType::from(255u16), QueryType::All);
+        assert_eq!(QueryType::from(9999u16), QueryType::Unknown(9999));
+    }
+}
@@ -0,0 +1,22 @@
+use std::convert::TryInto;
+
+use crate::{Error, Name};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub preference: u16,
+    pub exchange: Name<'a>,
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 15;
+
+    fn parse(rdata: &'a [u8], original: &'a [u8]) -> super::RDataResult<'a> {
+        let preference = u16::from_be_bytes(
+            rdata.get(0..2).ok_or(Error::WrongRdataLength)?.try_into().unwrap(),
+        );
+        let offset = rdata[2..].as_ptr() as usize - original.as_ptr() as usize;
+        let (exchange, _) = Name::parse(original, offset)?;
+        Ok(super::RData::MX(Record { preference, exchange }))
+    }
+}
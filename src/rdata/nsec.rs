@@ -0,0 +1,51 @@
+use crate::rdata::{bitmap, Type};
+use crate::{Error, Name};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub next_domain_name: Name<'a>,
+    pub types: Vec<Type>,
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 47;
+
+    fn parse(rdata: &'a [u8], original: &'a [u8]) -> super::RDataResult<'a> {
+        let offset = rdata.as_ptr() as usize - original.as_ptr() as usize;
+        // The next-owner-name in an NSEC record is part of what an
+        // RRSIG over it signs, so RFC4034 §6.2 requires it uncompressed.
+        let (next_domain_name, pos) = Name::parse_uncompressed(original, offset)?;
+        let bitmap_start = pos - offset;
+        let bitmap = rdata.get(bitmap_start..).ok_or(Error::WrongRdataLength)?;
+        Ok(super::RData::NSEC(Record {
+            next_domain_name,
+            types: bitmap::decode(bitmap),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Record;
+    use crate::rdata::{RData, Record as _, Type};
+
+    #[test]
+    fn parses_next_domain_name_and_type_bitmap() {
+        // next domain name "example.com.", then the RFC4034 §4.1.2
+        // worked example bitmap for A, MX, RRSIG, NSEC
+        let name = b"\x07example\x03com\x00";
+        let bitmap = [0x00, 0x06, 0x40, 0x01, 0x00, 0x00, 0x00, 0x03];
+        let mut original = vec![0u8, 0u8];
+        original.extend_from_slice(name);
+        original.extend_from_slice(&bitmap);
+        let rdata = &original[2..];
+
+        match Record::parse(rdata, &original).unwrap() {
+            RData::NSEC(r) => {
+                assert_eq!(r.next_domain_name.to_string(), "example.com.");
+                assert_eq!(r.types, vec![Type::A, Type::MX, Type::RRSIG, Type::NSEC]);
+            }
+            other => panic!("expected NSEC, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::convert::TryInto;
+
+use crate::rdata::{bitmap, Type};
+use crate::Error;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub hash_algorithm: u8,
+    pub flags: u8,
+    pub iterations: u16,
+    pub salt: &'a [u8],
+    pub next_hashed_owner_name: &'a [u8],
+    pub types: Vec<Type>,
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 50;
+
+    fn parse(rdata: &'a [u8], _original: &'a [u8]) -> super::RDataResult<'a> {
+        if rdata.len() < 5 {
+            return Err(Error::WrongRdataLength);
+        }
+        let hash_algorithm = rdata[0];
+        let flags = rdata[1];
+        let iterations = u16::from_be_bytes(rdata[2..4].try_into().unwrap());
+
+        let salt_len = rdata[4] as usize;
+        let mut pos = 5;
+        let salt = rdata.get(pos..pos + salt_len).ok_or(Error::WrongRdataLength)?;
+        pos += salt_len;
+
+        let hash_len = *rdata.get(pos).ok_or(Error::WrongRdataLength)? as usize;
+        pos += 1;
+        let next_hashed_owner_name = rdata.get(pos..pos + hash_len).ok_or(Error::WrongRdataLength)?;
+        pos += hash_len;
+
+        let types = bitmap::decode(rdata.get(pos..).ok_or(Error::WrongRdataLength)?);
+
+        Ok(super::RData::NSEC3(Record {
+            hash_algorithm,
+            flags,
+            iterations,
+            salt,
+            next_hashed_owner_name,
+            types,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Record;
+    use crate::rdata::{RData, Record as _, Type};
+    use crate::Error;
+
+    #[test]
+    fn parses_salt_hash_and_type_bitmap() {
+        let mut rdata = vec![1, 0]; // hash_algorithm = SHA-1, flags = 0
+        rdata.extend_from_slice(&12u16.to_be_bytes()); // iterations
+        rdata.push(2); // salt length
+        rdata.extend_from_slice(b"\xab\xcd"); // salt
+        rdata.push(4); // hash length
+        rdata.extend_from_slice(b"\x01\x02\x03\x04"); // next hashed owner name
+        rdata.extend_from_slice(&[0x00, 0x06, 0x40, 0x01, 0x00, 0x00, 0x00, 0x03]); // A, MX, RRSIG, NSEC
+
+        match Record::parse(&rdata, &rdata).unwrap() {
+            RData::NSEC3(r) => {
+                assert_eq!(r.hash_algorithm, 1);
+                assert_eq!(r.flags, 0);
+                assert_eq!(r.iterations, 12);
+                assert_eq!(r.salt, b"\xab\xcd");
+                assert_eq!(r.next_hashed_owner_name, b"\x01\x02\x03\x04");
+                assert_eq!(r.types, vec![Type::A, Type::MX, Type::RRSIG, Type::NSEC]);
+            }
+            other => panic!("expected NSEC3, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_salt_length_past_end_of_rdata() {
+        let rdata = [1, 0, 0, 12, 5, 0xab]; // salt length 5 but only 1 byte follows
+        assert!(matches!(Record::parse(&rdata, &rdata), Err(Error::WrongRdataLength)));
+    }
+}
@@ -0,0 +1,121 @@
+use std::convert::TryInto;
+
+use crate::ResponseCode;
+
+/// A single EDNS0 option carried in an OPT record's RDATA, e.g. EDNS
+/// Client Subnet (code 8, RFC7871) or COOKIE (code 10, RFC7873)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EdnsOption<'a> {
+    pub code: u16,
+    pub data: &'a [u8],
+}
+
+/// A parsed EDNS0 OPT pseudo-record (RFC6891 §6.1)
+///
+/// OPT repurposes the generic resource record fields instead of using
+/// its own: CLASS becomes the requestor's UDP payload size, and TTL is
+/// split into the high 8 bits of the extended RCODE, the EDNS version
+/// and a DO (DNSSEC OK) flag followed by 15 reserved bits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Record<'a> {
+    pub udp_payload_size: u16,
+    /// High 8 bits of the 12-bit extended response code
+    pub extended_rcode: u8,
+    pub version: u8,
+    pub dnssec_ok: bool,
+    rdata: &'a [u8],
+}
+
+impl<'a> Record<'a> {
+    /// Reinterpret an OPT resource record's CLASS, TTL and RDATA fields.
+    /// Unlike other record types, OPT can't be parsed through the
+    /// [`super::Record`] trait because it needs the raw CLASS/TTL values
+    /// rather than a `Class`/`u32` that's already been given spec meaning.
+    pub fn parse(class: u16, ttl: u32, rdata: &'a [u8]) -> Record<'a> {
+        let ttl = ttl.to_be_bytes();
+        Record {
+            udp_payload_size: class,
+            extended_rcode: ttl[0],
+            version: ttl[1],
+            dnssec_ok: ttl[2] & 0x80 != 0,
+            rdata,
+        }
+    }
+
+    /// Iterate over the `{option-code, option-length, data}` triples
+    /// carried in this record's RDATA
+    pub fn options(&self) -> EdnsOptions<'a> {
+        EdnsOptions { rest: self.rdata }
+    }
+
+    /// Combine this record's extended RCODE with the header's low 4
+    /// bits to produce the full 12-bit response code (e.g. BADVERS,
+    /// BADCOOKIE), which the plain 4-bit `Header::response_code` can't
+    /// represent on its own.
+    pub fn full_response_code(&self, header_code: ResponseCode) -> u16 {
+        (u16::from(self.extended_rcode) << 4) | u16::from(u8::from(header_code))
+    }
+}
+
+/// Iterator over the options carried by an [`Record`]
+#[derive(Debug, Clone)]
+pub struct EdnsOptions<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for EdnsOptions<'a> {
+    type Item = EdnsOption<'a>;
+
+    fn next(&mut self) -> Option<EdnsOption<'a>> {
+        if self.rest.len() < 4 {
+            return None;
+        }
+        let code = u16::from_be_bytes(self.rest[0..2].try_into().unwrap());
+        let len = u16::from_be_bytes(self.rest[2..4].try_into().unwrap()) as usize;
+        let data = self.rest.get(4..4 + len)?;
+        self.rest = &self.rest[4 + len..];
+        Some(EdnsOption { code, data })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Record;
+    use crate::ResponseCode;
+
+    #[test]
+    fn parse_sets_dnssec_ok_and_version() {
+        // class = UDP payload size 4096, ttl bytes: extended_rcode 0x01,
+        // version 0, DO bit set, reserved bits zero
+        let rdata = b"";
+        let record = Record::parse(4096, 0x0100_8000, rdata);
+        assert_eq!(record.udp_payload_size, 4096);
+        assert_eq!(record.extended_rcode, 0x01);
+        assert_eq!(record.version, 0);
+        assert!(record.dnssec_ok);
+    }
+
+    #[test]
+    fn parse_without_do_bit() {
+        let record = Record::parse(512, 0, b"");
+        assert!(!record.dnssec_ok);
+    }
+
+    #[test]
+    fn iterates_options() {
+        // COOKIE (code 10) option carrying 2 bytes of data
+        let rdata = b"\x00\x0a\x00\x02\xab\xcd";
+        let record = Record::parse(4096, 0, rdata);
+        let options: Vec<_> = record.options().collect();
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].code, 10);
+        assert_eq!(options[0].data, b"\xab\xcd");
+    }
+
+    #[test]
+    fn full_response_code_combines_extended_and_header_bits() {
+        // extended_rcode high byte 0x01, header's low 4 bits (NoError = 0)
+        let record = Record::parse(4096, 0x0100_0000, b"");
+        assert_eq!(record.full_response_code(ResponseCode::NoError), 0x10);
+    }
+}
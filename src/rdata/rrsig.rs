@@ -0,0 +1,109 @@
+use std::convert::TryInto;
+
+use crate::rdata::Type;
+use crate::{Error, Name};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub type_covered: Type,
+    pub algorithm: u8,
+    pub labels: u8,
+    pub original_ttl: u32,
+    pub signature_expiration: u32,
+    pub signature_inception: u32,
+    pub key_tag: u16,
+    pub signers_name: Name<'a>,
+    pub signature: &'a [u8],
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 46;
+
+    fn parse(rdata: &'a [u8], original: &'a [u8]) -> super::RDataResult<'a> {
+        let head = rdata.get(0..18).ok_or(Error::WrongRdataLength)?;
+        let rdata_start = rdata.as_ptr() as usize - original.as_ptr() as usize;
+        let rdata_end = rdata_start + rdata.len();
+        let offset = rdata_start + 18;
+        // The signer's name is part of what got signed, so RFC4034 §3.1.7
+        // forbids compressing it: a compression pointer would make the
+        // signed bytes depend on where the record happens to live.
+        let (signers_name, pos) = Name::parse_uncompressed(original, offset)?;
+        // The name can run past this record's own RDLENGTH (e.g. a
+        // truncated RDLENGTH in a crafted packet); bounds-check instead
+        // of indexing blindly into `original`.
+        let signature = original.get(pos..rdata_end).ok_or(Error::WrongRdataLength)?;
+        Ok(super::RData::RRSIG(Record {
+            type_covered: Type::from(u16::from_be_bytes(head[0..2].try_into().unwrap())),
+            algorithm: head[2],
+            labels: head[3],
+            original_ttl: u32::from_be_bytes(head[4..8].try_into().unwrap()),
+            signature_expiration: u32::from_be_bytes(head[8..12].try_into().unwrap()),
+            signature_inception: u32::from_be_bytes(head[12..16].try_into().unwrap()),
+            key_tag: u16::from_be_bytes(head[16..18].try_into().unwrap()),
+            signers_name,
+            signature,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Record;
+    use crate::rdata::{RData, Record as _, Type};
+    use crate::Error;
+
+    fn head() -> Vec<u8> {
+        let mut head = Vec::new();
+        head.extend_from_slice(&1u16.to_be_bytes()); // type covered: A
+        head.push(8); // algorithm
+        head.push(2); // labels
+        head.extend_from_slice(&3600u32.to_be_bytes()); // original_ttl
+        head.extend_from_slice(&2u32.to_be_bytes()); // signature_expiration
+        head.extend_from_slice(&1u32.to_be_bytes()); // signature_inception
+        head.extend_from_slice(&0xbeefu16.to_be_bytes()); // key_tag
+        head
+    }
+
+    #[test]
+    fn parses_fields_and_trailing_signature() {
+        // signer's name "example.com" in uncompressed wire form
+        let name = b"\x07example\x03com\x00";
+        let signature = b"\xab\xcd\xef\x01";
+        let mut original = vec![0u8, 0u8]; // unrelated prefix bytes
+        original.extend_from_slice(&head());
+        original.extend_from_slice(name);
+        original.extend_from_slice(signature);
+        let rdata = &original[2..];
+
+        match Record::parse(rdata, &original).unwrap() {
+            RData::RRSIG(r) => {
+                assert_eq!(r.type_covered, Type::A);
+                assert_eq!(r.algorithm, 8);
+                assert_eq!(r.labels, 2);
+                assert_eq!(r.original_ttl, 3600);
+                assert_eq!(r.signature_expiration, 2);
+                assert_eq!(r.signature_inception, 1);
+                assert_eq!(r.key_tag, 0xbeef);
+                assert_eq!(r.signers_name.to_string(), "example.com.");
+                assert_eq!(r.signature, signature);
+            }
+            other => panic!("expected RRSIG, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_rdlength_shorter_than_signers_name() {
+        // RDLENGTH only covers the 18-byte head, but the packet still has
+        // the full name and signature trailing in `original` (simulating a
+        // crafted/truncated RDLENGTH). This must not panic.
+        let name = b"\x07example\x03com\x00";
+        let signature = b"\xab\xcd\xef\x01";
+        let mut original = vec![0u8, 0u8];
+        original.extend_from_slice(&head());
+        original.extend_from_slice(name);
+        original.extend_from_slice(signature);
+        let rdata = &original[2..20]; // just the 18-byte head
+
+        assert!(matches!(Record::parse(rdata, &original), Err(Error::WrongRdataLength)));
+    }
+}
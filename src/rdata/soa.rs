@@ -0,0 +1,34 @@
+use std::convert::TryInto;
+
+use crate::{Error, Name};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub primary_ns: Name<'a>,
+    pub mailbox: Name<'a>,
+    pub serial: u32,
+    pub refresh: u32,
+    pub retry: u32,
+    pub expire: u32,
+    pub minimum_ttl: u32,
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 6;
+
+    fn parse(rdata: &'a [u8], original: &'a [u8]) -> super::RDataResult<'a> {
+        let offset = rdata.as_ptr() as usize - original.as_ptr() as usize;
+        let (primary_ns, pos) = Name::parse(original, offset)?;
+        let (mailbox, pos) = Name::parse(original, pos)?;
+        let tail = original.get(pos..pos + 20).ok_or(Error::WrongRdataLength)?;
+        Ok(super::RData::SOA(Record {
+            primary_ns,
+            mailbox,
+            serial: u32::from_be_bytes(tail[0..4].try_into().unwrap()),
+            refresh: u32::from_be_bytes(tail[4..8].try_into().unwrap()),
+            retry: u32::from_be_bytes(tail[8..12].try_into().unwrap()),
+            expire: u32::from_be_bytes(tail[12..16].try_into().unwrap()),
+            minimum_ttl: u32::from_be_bytes(tail[16..20].try_into().unwrap()),
+        }))
+    }
+}
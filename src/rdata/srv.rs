@@ -0,0 +1,27 @@
+use std::convert::TryInto;
+
+use crate::{Error, Name};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a> {
+    pub priority: u16,
+    pub weight: u16,
+    pub port: u16,
+    pub target: Name<'a>,
+}
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 33;
+
+    fn parse(rdata: &'a [u8], original: &'a [u8]) -> super::RDataResult<'a> {
+        if rdata.len() < 6 {
+            return Err(Error::WrongRdataLength);
+        }
+        let priority = u16::from_be_bytes(rdata[0..2].try_into().unwrap());
+        let weight = u16::from_be_bytes(rdata[2..4].try_into().unwrap());
+        let port = u16::from_be_bytes(rdata[4..6].try_into().unwrap());
+        let offset = rdata[6..].as_ptr() as usize - original.as_ptr() as usize;
+        let (target, _) = Name::parse(original, offset)?;
+        Ok(super::RData::SRV(Record { priority, weight, port, target }))
+    }
+}
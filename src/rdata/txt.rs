@@ -0,0 +1,19 @@
+use crate::Error;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record<'a>(pub Vec<&'a [u8]>);
+
+impl<'a> super::Record<'a> for Record<'a> {
+    const TYPE: isize = 16;
+
+    fn parse(mut rdata: &'a [u8], _original: &'a [u8]) -> super::RDataResult<'a> {
+        let mut strings = Vec::new();
+        while !rdata.is_empty() {
+            let len = rdata[0] as usize;
+            let text = rdata.get(1..1 + len).ok_or(Error::WrongRdataLength)?;
+            strings.push(text);
+            rdata = &rdata[1 + len..];
+        }
+        Ok(super::RData::TXT(Record(strings)))
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+use crate::rdata::{QueryType, RData};
+use crate::{Class, Header, Name, QueryClass};
+
+/// A fully parsed DNS packet
+#[derive(Debug)]
+pub struct Packet<'a> {
+    pub header: Header,
+    pub questions: Vec<Question<'a>>,
+    pub answers: Vec<ResourceRecord<'a>>,
+    pub nameservers: Vec<ResourceRecord<'a>>,
+    pub additional: Vec<ResourceRecord<'a>>,
+}
+
+/// An entry in the question section
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Question<'a> {
+    pub qname: Name<'a>,
+    /// mDNS "unicast-response" bit, repurposed from the top bit of the class
+    pub prefer_unicast: bool,
+    pub qtype: QueryType,
+    pub qclass: QueryClass,
+}
+
+/// An entry in the answer, authority or additional section
+#[derive(Debug, PartialEq)]
+pub struct ResourceRecord<'a> {
+    pub name: Name<'a>,
+    /// mDNS "cache-flush" bit, repurposed from the top bit of the class
+    pub multicast_unique: bool,
+    pub cls: Class,
+    pub ttl: u32,
+    pub data: RData<'a>,
+}
+
+impl<'a> fmt::Display for ResourceRecord<'a> {
+    /// Render this record in standard master-file (zone file)
+    /// presentation format, e.g. `example.com. 300 IN A 93.184.216.34`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {} {}", self.name, self.ttl, self.cls, self.data.type_code(), self.data)
+    }
+}
+
+impl<'a> ResourceRecord<'a> {
+    /// Render this record in standard master-file (zone file)
+    /// presentation format. Equivalent to [`ToString::to_string`].
+    pub fn to_zone_string(&self) -> String {
+        self.to_string()
+    }
+}